@@ -1,9 +1,12 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
-use chrono::{DateTime, Local, TimeZone, Datelike, NaiveDateTime};
+use chrono::{DateTime, Local, TimeZone, Datelike, NaiveDate, NaiveDateTime, Weekday};
 use eframe::egui;
 use egui::{menu, Color32};
+use notify_rust::Notification;
 use std::io::{Read, Write};
+use tray_icon::menu::{Menu, MenuEvent, MenuId, MenuItem};
+use tray_icon::{ClickType, TrayIcon, TrayIconBuilder, TrayIconEvent};
 
 fn load_icon(buffer: &[u8]) -> eframe::IconData {
     let (icon_rgba, icon_width, icon_height) = {
@@ -24,7 +27,29 @@ fn load_icon(buffer: &[u8]) -> eframe::IconData {
 
 const ICON: &[u8] = include_bytes!("../case_notifier.png");
 
+fn load_tray_icon(buffer: &[u8]) -> tray_icon::Icon {
+    let image = image::load_from_memory(buffer)
+        .expect("Failed to open icon path")
+        .into_rgba8();
+    let (width, height) = image.dimensions();
+    tray_icon::Icon::from_rgba(image.into_raw(), width, height)
+        .expect("Failed to create tray icon")
+}
+
+// tray-icon's Linux backend needs a gtk event loop pumped on its own thread
+// (separate from the winit loop eframe drives) to process tray/menu events,
+// but this project has no dependency on `gtk` to do that pump with; the tray
+// icon still shows up, it just won't be interactive on Linux until `gtk` is
+// added as a real dependency
+#[cfg(target_os = "linux")]
+fn spawn_tray_event_loop() {}
+
+#[cfg(not(target_os = "linux"))]
+fn spawn_tray_event_loop() {}
+
 fn main() -> Result<(), eframe::Error> {
+    spawn_tray_event_loop();
+
     let options = eframe::NativeOptions {
         icon_data: Some(load_icon(ICON)),
         initial_window_size: Some(egui::vec2(480.0, 560.0)),
@@ -52,14 +77,105 @@ fn format_time(time: u64) -> String {
     format!("{}:{:02}:{:02}:{:02}", days, hours, minutes, seconds)
 }
 
+fn notify_case_ready(account_name: &str) {
+    let result = Notification::new()
+        .summary("Case Notifier")
+        .body(&format!("{} case is ready", account_name))
+        .show();
+
+    if let Err(err) = result {
+        eprintln!("Failed to show notification: {}", err);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Schedule {
+    Weekly(Weekday),
+    EveryNDays(u32),
+    Daily,
+}
+
+impl Schedule {
+    fn label(&self) -> &'static str {
+        match self {
+            Schedule::Weekly(_) => "Weekly",
+            Schedule::EveryNDays(_) => "Every N days",
+            Schedule::Daily => "Daily",
+        }
+    }
+
+    fn next_occurrence(&self, from: u64) -> u64 {
+        match self {
+            Schedule::Weekly(weekday) => next_weekly(from, *weekday),
+            Schedule::EveryNDays(n) => next_every_n_days(from, *n),
+            Schedule::Daily => next_every_n_days(from, 1),
+        }
+    }
+
+    fn to_binary(&self) -> Vec<u8> {
+        match self {
+            Schedule::Weekly(weekday) => vec![0, weekday.num_days_from_monday() as u8],
+            Schedule::EveryNDays(n) => {
+                let mut data = vec![1];
+                data.extend_from_slice(&n.to_le_bytes());
+                data
+            }
+            Schedule::Daily => vec![2],
+        }
+    }
+}
+
+fn weekday_from_index(index: u8) -> Weekday {
+    match index % 7 {
+        0 => Weekday::Mon,
+        1 => Weekday::Tue,
+        2 => Weekday::Wed,
+        3 => Weekday::Thu,
+        4 => Weekday::Fri,
+        5 => Weekday::Sat,
+        _ => Weekday::Sun,
+    }
+}
+
+// None on a truncated or unrecognized tag, instead of panicking
+fn read_schedule<R: Read>(reader: &mut R) -> Option<Schedule> {
+    let mut tag = [0u8; 1];
+    reader.read_exact(&mut tag).ok()?;
+    match tag[0] {
+        0 => {
+            let mut weekday = [0u8; 1];
+            reader.read_exact(&mut weekday).ok()?;
+            Some(Schedule::Weekly(weekday_from_index(weekday[0])))
+        }
+        1 => {
+            let mut n = [0u8; 4];
+            reader.read_exact(&mut n).ok()?;
+            Some(Schedule::EveryNDays(u32::from_le_bytes(n)))
+        }
+        2 => Some(Schedule::Daily),
+        _ => None,
+    }
+}
+
 struct Account {
     name: String,
     date: u64,
+    was_ready: bool,
+    schedule: Schedule,
 }
 
 impl Account {
     fn new(name: String, date: u64) -> Self {
-        Self { name, date }
+        let mut account = Self {
+            name,
+            date,
+            was_ready: false,
+            schedule: Schedule::Weekly(Weekday::Wed),
+        };
+        // an account loaded (or created) already past its drop date shouldn't
+        // fire a notification the moment it appears
+        account.was_ready = account.get_remaining_time() == 0;
+        account
     }
 
     fn get_name(&self) -> &String {
@@ -71,7 +187,7 @@ impl Account {
     }
 
     fn get_next_date(&self) -> u64 {
-        next_wednesday(self.date)
+        self.schedule.next_occurrence(self.date)
     }
 
     fn get_remaining_time(&self) -> u64 {
@@ -83,91 +199,521 @@ impl Account {
         next - now
     }
 
+    // every drop date in [month_start, month_end]
+    fn occurrences_in_month(&self, month_start: NaiveDate, month_end: NaiveDate) -> Vec<NaiveDate> {
+        let mut occurrences = vec![];
+        let mut from = self.date;
+
+        // catch up to this month before collecting occurrences
+        for _ in 0..10_000 {
+            let next = self.schedule.next_occurrence(from);
+            let date = Local.timestamp_opt(next as i64, 0).unwrap().date_naive();
+            if date >= month_start {
+                break;
+            }
+            from = next;
+        }
+
+        for _ in 0..64 {
+            let next = self.schedule.next_occurrence(from);
+            let date = Local.timestamp_opt(next as i64, 0).unwrap().date_naive();
+            if date > month_end {
+                break;
+            }
+            if date >= month_start {
+                occurrences.push(date);
+            }
+            from = next;
+        }
+        occurrences
+    }
+
     fn to_binary(&self) -> Vec<u8> {
         let mut data = vec![];
-        data.extend_from_slice(self.name.as_bytes());
-        data.push(0);
+        let name_bytes = self.name.as_bytes();
+        data.extend_from_slice(&(name_bytes.len() as u32).to_le_bytes());
+        data.extend_from_slice(name_bytes);
         data.extend_from_slice(&self.date.to_le_bytes());
+        data.extend_from_slice(&self.schedule.to_binary());
         data
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum SortMode {
+    RemainingTime,
+    Name,
+    NextDrop,
+}
+
+impl SortMode {
+    fn label(&self) -> &'static str {
+        match self {
+            SortMode::RemainingTime => "Remaining time",
+            SortMode::Name => "Name",
+            SortMode::NextDrop => "Next drop",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ViewMode {
+    List,
+    Calendar,
+}
+
 struct CasesNotifier {
     accounts: Vec<Account>,
     editing_account: bool,
     account_to_edit: usize,
     editing_date: String,
+    tray_icon: Option<TrayIcon>,
+    tray_add_account_id: MenuId,
+    tray_quit_id: MenuId,
+    sort_mode: SortMode,
+    only_ready: bool,
+    view_mode: ViewMode,
+    calendar_scroll_to: Option<NaiveDate>,
+    should_quit: bool,
+    pending_hide: bool,
+    storage_warning: Option<&'static str>,
+}
+
+fn build_tray_icon() -> (Option<TrayIcon>, MenuId, MenuId) {
+    let add_account_item = MenuItem::new("Add account", true, None);
+    let quit_item = MenuItem::new("Quit", true, None);
+    let add_account_id = add_account_item.id().clone();
+    let quit_id = quit_item.id().clone();
+
+    let tray_menu = Menu::new();
+    if let Err(err) = tray_menu.append_items(&[&add_account_item, &quit_item]) {
+        eprintln!("Failed to build tray menu: {}", err);
+    }
+
+    let tray_icon = TrayIconBuilder::new()
+        .with_menu(Box::new(tray_menu))
+        .with_tooltip("Cases Notifier")
+        .with_icon(load_tray_icon(ICON))
+        .build();
+
+    match tray_icon {
+        Ok(tray_icon) => (Some(tray_icon), add_account_id, quit_id),
+        Err(err) => {
+            eprintln!("Failed to create tray icon: {}", err);
+            (None, add_account_id, quit_id)
+        }
+    }
 }
 
 impl Default for CasesNotifier {
     fn default() -> Self {
+        let (tray_icon, tray_add_account_id, tray_quit_id) = build_tray_icon();
+
+        let (accounts, storage_warning) = load_accounts().unwrap_or_else(|err| {
+            eprintln!("Failed to load accounts: {}", err);
+            (vec![], None)
+        });
+
         Self {
-            accounts: load_accounts(),
+            accounts,
             editing_account: false,
             account_to_edit: 0,
             editing_date: "".to_string(),
+            tray_icon,
+            tray_add_account_id,
+            tray_quit_id,
+            sort_mode: SortMode::RemainingTime,
+            only_ready: false,
+            view_mode: ViewMode::List,
+            calendar_scroll_to: None,
+            should_quit: false,
+            pending_hide: false,
+            storage_warning,
+        }
+    }
+}
+
+// the magic marks the current record layout (the version byte after it is
+// written but not checked on load); anything else falls back to the legacy
+// layouts
+const ACCOUNTS_MAGIC: &[u8; 4] = b"CNAD";
+const ACCOUNTS_FORMAT_VERSION: u8 = 1;
+
+fn save_accounts(accounts: &Vec<Account>) -> std::io::Result<()> {
+    let tmp_path = "accounts.dat.tmp";
+    {
+        let mut file = std::fs::File::create(tmp_path)?;
+        file.write_all(ACCOUNTS_MAGIC)?;
+        file.write_all(&[ACCOUNTS_FORMAT_VERSION])?;
+        for account in accounts {
+            file.write_all(&account.to_binary())?;
         }
+        file.sync_all()?;
     }
+    // rename is atomic, so a crash mid-write leaves the previous file intact
+    std::fs::rename(tmp_path, "accounts.dat")
 }
 
-fn save_accounts(accounts: &Vec<Account>) {
-    let mut file = std::fs::File::create("accounts.dat").unwrap();
-    for account in accounts {
-        file.write_all(&account.to_binary()).unwrap();
+fn save_accounts_or_log(accounts: &Vec<Account>) {
+    if let Err(err) = save_accounts(accounts) {
+        eprintln!("Failed to save accounts: {}", err);
+    }
+}
+
+// also shown as a dismissible banner, since release builds have no console
+const CORRUPT_ACCOUNTS_WARNING: &str =
+    "accounts.dat had corrupted/truncated data; some accounts may be missing";
+
+fn load_accounts() -> std::io::Result<(Vec<Account>, Option<&'static str>)> {
+    let bytes = match std::fs::read("accounts.dat") {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok((vec![], None)),
+        Err(err) => return Err(err),
+    };
+
+    if bytes.starts_with(ACCOUNTS_MAGIC) {
+        // a truncated header yields no accounts plus the corruption warning,
+        // not a panic
+        if bytes.len() <= ACCOUNTS_MAGIC.len() {
+            eprintln!("accounts.dat: truncated header, starting with no accounts");
+            return Ok((vec![], Some(CORRUPT_ACCOUNTS_WARNING)));
+        }
+        let mut reader = std::io::Cursor::new(&bytes[ACCOUNTS_MAGIC.len() + 1..]);
+        let (accounts, truncated) = read_accounts(&mut reader);
+        Ok((accounts, truncated.then_some(CORRUPT_ACCOUNTS_WARNING)))
+    } else {
+        let mut reader = std::io::Cursor::new(bytes.as_slice());
+        let (accounts, truncated) = read_legacy_accounts(&mut reader);
+        Ok((accounts, truncated.then_some(CORRUPT_ACCOUNTS_WARNING)))
     }
 }
 
-fn load_accounts() -> Vec<Account> {
+// reject implausible lengths before allocating
+const MAX_ACCOUNT_NAME_LEN: usize = 4096;
+
+// u32 name length, name bytes, u64 date, schedule bytes; the bool return
+// says whether a record was dropped to corruption, vs a clean EOF
+fn read_accounts<R: Read>(reader: &mut R) -> (Vec<Account>, bool) {
     let mut accounts = vec![];
-    if let Ok(file) = std::fs::File::open("accounts.dat") {
-        let mut reader = std::io::BufReader::new(file);
-        let mut buffer = vec![];
-        loop {
-            let mut byte = [0; 1];
-            if reader.read(&mut byte).unwrap() == 0 {
+    let mut truncated = false;
+    loop {
+        let mut len_bytes = [0u8; 4];
+        match reader.read(&mut len_bytes[..1]) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        if reader.read_exact(&mut len_bytes[1..]).is_err() {
+            eprintln!("accounts.dat: truncated record, stopping");
+            truncated = true;
+            break;
+        }
+        let name_len = u32::from_le_bytes(len_bytes) as usize;
+        if name_len > MAX_ACCOUNT_NAME_LEN {
+            eprintln!("accounts.dat: implausible name length, stopping");
+            truncated = true;
+            break;
+        }
+
+        let mut name_bytes = vec![0u8; name_len];
+        if reader.read_exact(&mut name_bytes).is_err() {
+            eprintln!("accounts.dat: truncated record, stopping");
+            truncated = true;
+            break;
+        }
+        let name = match String::from_utf8(name_bytes) {
+            Ok(name) => name,
+            Err(_) => {
+                eprintln!("accounts.dat: record with invalid UTF-8 name, stopping");
+                truncated = true;
                 break;
             }
-            if byte[0] == 0 {
-                let name = String::from_utf8(buffer.clone()).unwrap();
-                buffer.clear();
-                let mut date = [0; 8];
-                reader.read_exact(&mut date).unwrap();
-                let date = u64::from_le_bytes(date);
-                accounts.push(Account::new(name, date));
-            } else {
-                buffer.push(byte[0]);
+        };
+
+        let mut date_bytes = [0u8; 8];
+        if reader.read_exact(&mut date_bytes).is_err() {
+            eprintln!("accounts.dat: truncated record, stopping");
+            truncated = true;
+            break;
+        }
+        let date = u64::from_le_bytes(date_bytes);
+
+        let schedule = match read_schedule(reader) {
+            Some(schedule) => schedule,
+            None => {
+                eprintln!("accounts.dat: truncated record, stopping");
+                truncated = true;
+                break;
             }
+        };
+
+        let mut account = Account::new(name, date);
+        account.schedule = schedule;
+        account.was_ready = account.get_remaining_time() == 0;
+        accounts.push(account);
+    }
+    (accounts, truncated)
+}
+
+// pre-length-prefixed format: nul-terminated name, u64 date, and (if 0xFF-
+// marked) schedule bytes; the bool return says dropped-to-corruption vs EOF
+fn read_legacy_accounts<R: Read>(reader: &mut R) -> (Vec<Account>, bool) {
+    const MARKER: u8 = 0xFF;
+
+    let mut first_byte = [0u8; 1];
+    let versioned = match reader.read_exact(&mut first_byte) {
+        Ok(()) if first_byte[0] == MARKER => {
+            let mut version = [0u8; 1];
+            if reader.read_exact(&mut version).is_err() {
+                return (vec![], true);
+            }
+            true
         }
+        Ok(()) => false,
+        Err(_) => return (vec![], false),
+    };
+
+    let mut accounts = vec![];
+    let mut truncated = false;
+    let mut buffer = if versioned { vec![] } else { vec![first_byte[0]] };
+    loop {
+        let mut byte = [0u8; 1];
+        match reader.read(&mut byte) {
+            Ok(0) => {
+                if !buffer.is_empty() {
+                    eprintln!("accounts.dat: truncated legacy record, stopping");
+                    truncated = true;
+                }
+                break;
+            }
+            Err(_) => break,
+            Ok(_) => {}
+        }
+
+        if byte[0] != 0 {
+            buffer.push(byte[0]);
+            continue;
+        }
+
+        let name = match String::from_utf8(std::mem::take(&mut buffer)) {
+            Ok(name) => name,
+            Err(_) => {
+                eprintln!("accounts.dat: legacy record with invalid UTF-8 name, stopping");
+                truncated = true;
+                break;
+            }
+        };
+
+        let mut date_bytes = [0u8; 8];
+        if reader.read_exact(&mut date_bytes).is_err() {
+            eprintln!("accounts.dat: truncated legacy record, stopping");
+            truncated = true;
+            break;
+        }
+        let date = u64::from_le_bytes(date_bytes);
+
+        let mut account = Account::new(name, date);
+        if versioned {
+            match read_schedule(reader) {
+                Some(schedule) => account.schedule = schedule,
+                None => {
+                    eprintln!("accounts.dat: truncated legacy record, stopping");
+                    truncated = true;
+                    break;
+                }
+            }
+            account.was_ready = account.get_remaining_time() == 0;
+        }
+        accounts.push(account);
     }
-    accounts
+    (accounts, truncated)
 }
 
-fn next_wednesday(timestamp: u64) -> u64 {
-    let datetime = NaiveDateTime::from_timestamp_opt(timestamp as i64, 0).unwrap();
+fn next_weekly(timestamp: u64, weekday: Weekday) -> u64 {
+    let date = Local.timestamp_opt(timestamp as i64, 0).unwrap().date_naive();
 
-    let mut next_wednesday = datetime.date().succ_opt().unwrap();
-    while next_wednesday.weekday() != chrono::Weekday::Wed {
-        next_wednesday = next_wednesday.succ_opt().unwrap();
+    let mut next_day = date.succ_opt().unwrap();
+    while next_day.weekday() != weekday {
+        next_day = next_day.succ_opt().unwrap();
     }
 
-    let next_wednesday_utc = NaiveDateTime::new(next_wednesday, chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap());
-    next_wednesday_utc.timestamp() as u64
+    // 00:00 local; where a DST change skips midnight, 01:00 local instead
+    let midnight = NaiveDateTime::new(next_day, chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+    let next_day_local = Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| Local.from_local_datetime(&(midnight + chrono::Duration::hours(1))).earliest())
+        .unwrap();
+    next_day_local.timestamp() as u64
+}
+
+// the first and last day of the month `date` falls in
+fn month_bounds(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let month_start = date.with_day(1).unwrap();
+    let next_month_start = if month_start.month() == 12 {
+        NaiveDate::from_ymd_opt(month_start.year() + 1, 1, 1).unwrap()
+    } else {
+        NaiveDate::from_ymd_opt(month_start.year(), month_start.month() + 1, 1).unwrap()
+    };
+    let month_end = next_month_start.pred_opt().unwrap();
+    (month_start, month_end)
+}
+
+// the occurrence right after `last_drop`; like `next_weekly` this can land in
+// the past, which is how `get_remaining_time` recognizes an account as ready
+fn next_every_n_days(last_drop: u64, n: u32) -> u64 {
+    let datetime = NaiveDateTime::from_timestamp_opt(last_drop as i64, 0).unwrap();
+    let midnight = NaiveDateTime::new(datetime.date(), chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+    let step = n.max(1) as i64 * 86400;
+    (midnight.timestamp() + step) as u64
+}
+
+impl CasesNotifier {
+    fn add_account(&mut self) {
+        self.accounts.push(Account::new(
+            "Account name".to_string(),
+            chrono::Utc::now().timestamp() as u64,
+        ));
+        self.editing_account = true;
+        self.account_to_edit = self.accounts.len() - 1;
+        self.editing_date = format_date(self.accounts[self.account_to_edit].get_date());
+        save_accounts_or_log(&self.accounts);
+    }
+
+    // indices into `self.accounts`, filtered/sorted for display; on-disk order
+    // (and therefore Edit/Delete/Reset, which index into `self.accounts`
+    // directly) is left untouched
+    fn display_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.accounts.len())
+            .filter(|&i| !self.only_ready || self.accounts[i].get_remaining_time() == 0)
+            .collect();
+
+        indices.sort_by(|&a, &b| {
+            let a = &self.accounts[a];
+            let b = &self.accounts[b];
+            match self.sort_mode {
+                SortMode::RemainingTime => a.get_remaining_time().cmp(&b.get_remaining_time()),
+                SortMode::Name => a.get_name().cmp(b.get_name()),
+                SortMode::NextDrop => a.get_next_date().cmp(&b.get_next_date()),
+            }
+        });
+
+        indices
+    }
+
+    // fires the ready notification; runs every frame regardless of view_mode
+    fn notify_ready_accounts(&mut self) {
+        for account in &mut self.accounts {
+            let is_ready = account.get_remaining_time() == 0;
+            if is_ready && !account.was_ready {
+                notify_case_ready(account.get_name());
+            }
+            account.was_ready = is_ready;
+        }
+    }
+
+    // month grid of the month containing today; each day cell tallies the accounts whose
+    // schedule lands a drop on it, clicking one scrolls the list view there
+    fn render_calendar(&mut self, ui: &mut egui::Ui) {
+        let today = chrono::Local::now().date_naive();
+        let (month_start, month_end) = month_bounds(today);
+
+        let mut drops_by_day: std::collections::HashMap<NaiveDate, usize> = std::collections::HashMap::new();
+        for account in &self.accounts {
+            for date in account.occurrences_in_month(month_start, month_end) {
+                *drops_by_day.entry(date).or_insert(0) += 1;
+            }
+        }
+
+        ui.label(egui::RichText::new(month_start.format("%B %Y").to_string()).strong().size(18.0));
+
+        egui::Grid::new("calendar_grid").spacing([4.0, 4.0]).show(ui, |ui| {
+            for weekday in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"] {
+                ui.label(egui::RichText::new(weekday).strong());
+            }
+            ui.end_row();
+
+            for _ in 0..month_start.weekday().num_days_from_monday() {
+                ui.label("");
+            }
+
+            let mut column = month_start.weekday().num_days_from_monday();
+            let mut day = month_start;
+            while day <= month_end {
+                let count = drops_by_day.get(&day).copied().unwrap_or(0);
+                let label = if count > 0 {
+                    format!("{}\n{} ready", day.day(), count)
+                } else {
+                    format!("{}", day.day())
+                };
+                let color = if count > 0 {
+                    Color32::from_rgb(50, 150, 75)
+                } else {
+                    ui.visuals().widgets.inactive.bg_fill
+                };
+
+                if ui.add(egui::Button::new(label).fill(color)).clicked() && count > 0 {
+                    self.calendar_scroll_to = Some(day);
+                    self.view_mode = ViewMode::List;
+                }
+
+                column += 1;
+                if column == 7 {
+                    ui.end_row();
+                    column = 0;
+                }
+                day = day.succ_opt().unwrap();
+            }
+        });
+    }
 }
 
 impl eframe::App for CasesNotifier {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        // the close control was clicked last frame and `on_close_event`
+        // vetoed the close; actually hide the window now that we have a Frame
+        if self.pending_hide {
+            self.pending_hide = false;
+            frame.set_visible(false);
+        }
+
+        // restore the window on a tray icon left-click
+        while let Ok(event) = TrayIconEvent::receiver().try_recv() {
+            if event.click_type == ClickType::Left {
+                frame.set_visible(true);
+                frame.set_minimized(false);
+            }
+        }
+
+        // "Add account"/"Quit" fired from the tray context menu
+        while let Ok(event) = MenuEvent::receiver().try_recv() {
+            if event.id == self.tray_add_account_id {
+                if !self.editing_account {
+                    self.add_account();
+                }
+            } else if event.id == self.tray_quit_id {
+                self.should_quit = true;
+                frame.close();
+            }
+        }
+
+        self.notify_ready_accounts();
+
         egui::CentralPanel::default().show(ctx, |ui| {
+            // storage warning banner
+            if let Some(warning) = self.storage_warning {
+                ui.horizontal(|ui| {
+                    ui.colored_label(Color32::from_rgb(255, 200, 0), warning);
+                    if ui.small_button("x").clicked() {
+                        self.storage_warning = None;
+                    }
+                });
+            }
+
             // menu bar
             menu::bar(ui, |ui| {
                 if ui.button("Add account").clicked() && !self.editing_account {
-                    self.accounts.push(Account::new(
-                        "Account name".to_string(),
-                        chrono::Utc::now().timestamp() as u64,
-                    ));
-                    self.editing_account = true;
-                    self.account_to_edit = self.accounts.len() - 1;
-                    self.editing_date = format_date(self.accounts[self.account_to_edit].get_date());
-                    save_accounts(&self.accounts);
+                    self.add_account();
                 }
 
                 let mut count = 0;
@@ -177,57 +723,116 @@ impl eframe::App for CasesNotifier {
                     }
                 }
                 ui.label(format!("Accounts ready: {}/{}", count, self.accounts.len()));
-            });
 
-            // scrollable area
-            egui::ScrollArea::vertical().show(ui, |ui| {
-                let mut to_delete_index = -1;
-                let mut to_save = false;
-
-                for (i, account) in &mut self.accounts.iter_mut().enumerate() {
-                    ui.label(egui::RichText::new(account.get_name()).strong().size(18.0));
-                    ui.label(format!("Last drop: {}", format_date(account.get_date())));
-                    ui.label(format!(
-                        "Next drop: {}",
-                        format_date(account.get_next_date())
-                    ));
-                    let remaining_time = account.get_remaining_time();
-                    if remaining_time > 0 {
-                        ui.colored_label(Color32::from_rgb(255, 50, 75), format!("Remaining: {}", format_time(remaining_time)));
-                    } else {
-                        ui.colored_label(Color32::from_rgb(50, 255, 75), "Ready!");
+                if let Some(tray_icon) = &self.tray_icon {
+                    let tooltip = format!("Accounts ready: {}/{}", count, self.accounts.len());
+                    if let Err(err) = tray_icon.set_tooltip(Some(tooltip)) {
+                        eprintln!("Failed to update tray tooltip: {}", err);
                     }
-
-                    ui.horizontal(|ui| {
-                        if ui.button("Edit").clicked() && !self.editing_account {
-                            self.editing_account = true;
-                            self.account_to_edit = i;
-                            self.editing_date = format_date(account.get_date());
-                        }
-
-                        if ui.button("Delete").clicked() && !self.editing_account {
-                            to_delete_index = i as i32;
-                        }
-
-                        if ui.button("Reset timer").clicked() && !self.editing_account {
-                            account.date = chrono::Utc::now().timestamp() as u64;
-                            to_save = true;
-                        }
-                    });
-
-                    ui.separator();
                 }
 
-                if to_delete_index != -1 {
-                    self.accounts.remove(to_delete_index as usize);
-                    to_save = true;
+                let view_toggle_label = match self.view_mode {
+                    ViewMode::List => "Calendar view",
+                    ViewMode::Calendar => "List view",
+                };
+                if ui.button(view_toggle_label).clicked() {
+                    self.view_mode = match self.view_mode {
+                        ViewMode::List => ViewMode::Calendar,
+                        ViewMode::Calendar => ViewMode::List,
+                    };
                 }
 
-                if to_save {
-                    save_accounts(&self.accounts);
+                if ui.button("Minimize to tray").clicked() {
+                    frame.set_visible(false);
                 }
             });
 
+            if self.view_mode == ViewMode::Calendar {
+                self.render_calendar(ui);
+            } else {
+                // list toolbar
+                ui.horizontal(|ui| {
+                    ui.label("Sort by:");
+                    egui::ComboBox::from_id_source("sort_mode")
+                        .selected_text(self.sort_mode.label())
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.sort_mode, SortMode::RemainingTime, SortMode::RemainingTime.label());
+                            ui.selectable_value(&mut self.sort_mode, SortMode::Name, SortMode::Name.label());
+                            ui.selectable_value(&mut self.sort_mode, SortMode::NextDrop, SortMode::NextDrop.label());
+                        });
+
+                    ui.checkbox(&mut self.only_ready, "Show only ready");
+                });
+
+                // scrollable area
+                let scroll_to_date = self.calendar_scroll_to.take();
+                let scroll_month_bounds = scroll_to_date.map(month_bounds);
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    let mut to_delete_index = -1;
+                    let mut to_save = false;
+
+                    for i in self.display_indices() {
+                        let account = &mut self.accounts[i];
+                        // a clicked calendar day can be any occurrence in the
+                        // month, not just the account's immediate next drop
+                        let occurs_on_scroll_day = match (scroll_to_date, scroll_month_bounds) {
+                            (Some(target), Some((month_start, month_end))) => account
+                                .occurrences_in_month(month_start, month_end)
+                                .contains(&target),
+                            _ => false,
+                        };
+
+                        let row = ui.vertical(|ui| {
+                            ui.label(egui::RichText::new(account.get_name()).strong().size(18.0));
+                            ui.label(format!("Last drop: {}", format_date(account.get_date())));
+                            ui.label(format!(
+                                "Next drop: {}",
+                                format_date(account.get_next_date())
+                            ));
+                            let remaining_time = account.get_remaining_time();
+
+                            if remaining_time > 0 {
+                                ui.colored_label(Color32::from_rgb(255, 50, 75), format!("Remaining: {}", format_time(remaining_time)));
+                            } else {
+                                ui.colored_label(Color32::from_rgb(50, 255, 75), "Ready!");
+                            }
+
+                            ui.horizontal(|ui| {
+                                if ui.button("Edit").clicked() && !self.editing_account {
+                                    self.editing_account = true;
+                                    self.account_to_edit = i;
+                                    self.editing_date = format_date(account.get_date());
+                                }
+
+                                if ui.button("Delete").clicked() && !self.editing_account {
+                                    to_delete_index = i as i32;
+                                }
+
+                                if ui.button("Reset timer").clicked() && !self.editing_account {
+                                    account.date = chrono::Utc::now().timestamp() as u64;
+                                    to_save = true;
+                                }
+                            });
+
+                            ui.separator();
+                        });
+
+                        if occurs_on_scroll_day {
+                            row.response.scroll_to_me(Some(egui::Align::Center));
+                        }
+                    }
+
+                    if to_delete_index != -1 {
+                        self.accounts.remove(to_delete_index as usize);
+                        to_save = true;
+                    }
+
+                    if to_save {
+                        save_accounts_or_log(&self.accounts);
+                    }
+                });
+            }
+
             // edit account
             if self.editing_account {
                 let mut show_window = true;
@@ -263,11 +868,59 @@ impl eframe::App for CasesNotifier {
                             }
                             Err(_) => {}
                         }
+
+                        ui.horizontal(|ui| {
+                            ui.label("Schedule:");
+                            let account = &mut self.accounts[self.account_to_edit];
+                            egui::ComboBox::from_id_source("schedule_kind")
+                                .selected_text(account.schedule.label())
+                                .show_ui(ui, |ui| {
+                                    if ui.selectable_label(matches!(account.schedule, Schedule::Weekly(_)), "Weekly").clicked() {
+                                        account.schedule = Schedule::Weekly(Weekday::Wed);
+                                    }
+                                    if ui.selectable_label(matches!(account.schedule, Schedule::EveryNDays(_)), "Every N days").clicked() {
+                                        account.schedule = Schedule::EveryNDays(7);
+                                    }
+                                    if ui.selectable_label(matches!(account.schedule, Schedule::Daily), "Daily").clicked() {
+                                        account.schedule = Schedule::Daily;
+                                    }
+                                });
+                        });
+
+                        match &mut self.accounts[self.account_to_edit].schedule {
+                            Schedule::Weekly(weekday) => {
+                                ui.horizontal(|ui| {
+                                    ui.label("Day of week:");
+                                    egui::ComboBox::from_id_source("schedule_weekday")
+                                        .selected_text(weekday.to_string())
+                                        .show_ui(ui, |ui| {
+                                            for wd in [
+                                                Weekday::Mon,
+                                                Weekday::Tue,
+                                                Weekday::Wed,
+                                                Weekday::Thu,
+                                                Weekday::Fri,
+                                                Weekday::Sat,
+                                                Weekday::Sun,
+                                            ] {
+                                                ui.selectable_value(weekday, wd, wd.to_string());
+                                            }
+                                        });
+                                });
+                            }
+                            Schedule::EveryNDays(n) => {
+                                ui.horizontal(|ui| {
+                                    ui.label("Every N days:");
+                                    ui.add(egui::DragValue::new(n).clamp_range(1..=365));
+                                });
+                            }
+                            Schedule::Daily => {}
+                        }
                     });
 
                 if !show_window {
                     self.editing_account = false;
-                    save_accounts(&self.accounts);
+                    save_accounts_or_log(&self.accounts);
                 }
             }
 
@@ -275,4 +928,122 @@ impl eframe::App for CasesNotifier {
             ctx.request_repaint_after(std::time::Duration::from_secs(1));
         });
     }
+
+    // the title-bar/taskbar close control should behave like "Minimize to
+    // tray", not exit the app outright; only the tray "Quit" item (which
+    // sets `should_quit` before closing) is allowed through. `on_close_event`
+    // has no `Frame` to hide the window with, so just veto the close and let
+    // the next `update` call do the actual hiding.
+    fn on_close_event(&mut self) -> bool {
+        if self.should_quit {
+            true
+        } else {
+            self.pending_hide = true;
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_weekly_lands_on_the_requested_weekday() {
+        let start = NaiveDate::from_ymd_opt(2026, 7, 26).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let timestamp = Local.from_local_datetime(&start).unwrap().timestamp() as u64;
+
+        let next = next_weekly(timestamp, Weekday::Mon);
+
+        let next_date = Local.timestamp_opt(next as i64, 0).unwrap().date_naive();
+        assert_eq!(next_date.weekday(), Weekday::Mon);
+        assert!(next > timestamp);
+    }
+
+    #[test]
+    fn next_every_n_days_can_land_in_the_past() {
+        let last_drop = Local::now().timestamp() as u64 - 5 * 86400;
+
+        let next = next_every_n_days(last_drop, 1);
+
+        assert!(next < Local::now().timestamp() as u64);
+    }
+
+    #[test]
+    fn month_bounds_spans_the_whole_month() {
+        let (start, end) = month_bounds(NaiveDate::from_ymd_opt(2026, 2, 14).unwrap());
+
+        assert_eq!(start, NaiveDate::from_ymd_opt(2026, 2, 1).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2026, 2, 28).unwrap());
+    }
+
+    #[test]
+    fn occurrences_in_month_catches_up_from_a_stale_last_drop() {
+        let last_drop = Local.from_local_datetime(
+            &NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        ).unwrap().timestamp() as u64;
+        let mut account = Account::new("Test".to_string(), last_drop);
+        account.schedule = Schedule::EveryNDays(30);
+
+        let (month_start, month_end) = month_bounds(NaiveDate::from_ymd_opt(2026, 7, 1).unwrap());
+        let occurrences = account.occurrences_in_month(month_start, month_end);
+
+        assert!(occurrences.iter().all(|date| *date >= month_start && *date <= month_end));
+    }
+
+    #[test]
+    fn schedule_binary_round_trips() {
+        for schedule in [Schedule::Weekly(Weekday::Fri), Schedule::EveryNDays(9), Schedule::Daily] {
+            let mut reader = std::io::Cursor::new(schedule.to_binary());
+            assert_eq!(read_schedule(&mut reader), Some(schedule));
+        }
+    }
+
+    #[test]
+    fn read_schedule_rejects_unknown_tags() {
+        let mut reader = std::io::Cursor::new(vec![3u8]);
+        assert_eq!(read_schedule(&mut reader), None);
+    }
+
+    #[test]
+    fn read_accounts_round_trips_through_to_binary() {
+        let mut account = Account::new("Alice".to_string(), 1_700_000_000);
+        account.schedule = Schedule::EveryNDays(14);
+
+        let mut reader = std::io::Cursor::new(account.to_binary());
+        let (accounts, truncated) = read_accounts(&mut reader);
+
+        assert!(!truncated);
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].get_name(), "Alice");
+        assert_eq!(accounts[0].get_date(), 1_700_000_000);
+        assert!(accounts[0].schedule == Schedule::EveryNDays(14));
+    }
+
+    #[test]
+    fn read_accounts_flags_a_truncated_record() {
+        let account = Account::new("Bob".to_string(), 1_700_000_000);
+        let mut data = account.to_binary();
+        data.truncate(data.len() - 1);
+
+        let mut reader = std::io::Cursor::new(data);
+        let (accounts, truncated) = read_accounts(&mut reader);
+
+        assert!(accounts.is_empty());
+        assert!(truncated);
+    }
+
+    #[test]
+    fn read_legacy_accounts_parses_nul_terminated_names() {
+        let mut data = b"Carol".to_vec();
+        data.push(0);
+        data.extend_from_slice(&1_700_000_000u64.to_le_bytes());
+
+        let mut reader = std::io::Cursor::new(data);
+        let (accounts, truncated) = read_legacy_accounts(&mut reader);
+
+        assert!(!truncated);
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].get_name(), "Carol");
+    }
 }